@@ -0,0 +1,244 @@
+use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
+use base64::Engine as _;
+use once_cell::sync::Lazy;
+use opentelemetry::propagation::text_map_propagator::FieldIter;
+use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId};
+use opentelemetry::Context;
+
+use crate::google_trace_context_propagator::GoogleTraceContextPropagator;
+
+/// Propagates span context in the binary Census/Cloud Trace format, using the
+/// __grpc-trace-bin__ metadata key.
+///
+/// GCP's gRPC path propagates trace context as 29 raw bytes rather than the textual
+/// `X-Cloud-Trace-Context` header used by [`GoogleTraceContextPropagator`]. For carriers
+/// that can only hold string values (e.g. HTTP headers), the bytes are base64-encoded.
+///
+/// See https://github.com/census-instrumentation/opencensus-specs/blob/master/encodings/BinaryEncoding.md
+/// for details on the binary format.
+#[derive(Clone, Debug, Default)]
+pub struct GoogleBinaryTraceContextPropagator {
+    _private: (),
+}
+
+const GRPC_TRACE_BIN_HEADER: &str = "grpc-trace-bin";
+
+const BINARY_FORMAT_VERSION: u8 = 0;
+const BINARY_TRACE_ID_FIELD_ID: u8 = 0;
+const BINARY_SPAN_ID_FIELD_ID: u8 = 1;
+const BINARY_TRACE_OPTIONS_FIELD_ID: u8 = 2;
+
+// 1 version byte + (1 field id + 16 trace id) + (1 field id + 8 span id) + (1 field id + 1 options)
+const BINARY_FORMAT_LEN: usize = 29;
+
+static GRPC_TRACE_BIN_HEADER_FIELDS: Lazy<[String; 1]> =
+    Lazy::new(|| [GRPC_TRACE_BIN_HEADER.to_owned()]);
+
+impl GoogleBinaryTraceContextPropagator {
+    /// Create a new `GoogleBinaryTraceContextPropagator`.
+    pub fn new() -> Self {
+        GoogleBinaryTraceContextPropagator { _private: () }
+    }
+
+    fn extract_span_context(&self, extractor: &dyn Extractor) -> Result<SpanContext, ()> {
+        let header_value = extractor.get(GRPC_TRACE_BIN_HEADER).ok_or(())?;
+        let bytes = BASE64_ENGINE.decode(header_value.trim()).map_err(|_| ())?;
+        Self::decode_binary(&bytes)
+    }
+
+    fn decode_binary(bytes: &[u8]) -> Result<SpanContext, ()> {
+        if bytes.len() != BINARY_FORMAT_LEN {
+            return Err(());
+        }
+
+        if bytes[0] != BINARY_FORMAT_VERSION {
+            return Err(());
+        }
+
+        if bytes[1] != BINARY_TRACE_ID_FIELD_ID {
+            return Err(());
+        }
+        let trace_id = TraceId::from_bytes(bytes[2..18].try_into().map_err(|_| ())?);
+
+        if bytes[18] != BINARY_SPAN_ID_FIELD_ID {
+            return Err(());
+        }
+        let span_id = SpanId::from_bytes(bytes[19..27].try_into().map_err(|_| ())?);
+
+        if bytes[27] != BINARY_TRACE_OPTIONS_FIELD_ID {
+            return Err(());
+        }
+        let trace_flags = if bytes[28] & 0x1 == 1 {
+            TraceFlags::SAMPLED
+        } else {
+            TraceFlags::NOT_SAMPLED
+        };
+
+        // reuse the textual propagator's validity check so invalid (e.g. all-zero) ids are rejected
+        GoogleTraceContextPropagator::build_span_context(trace_id, span_id, trace_flags)
+    }
+
+    fn encode_binary(span_context: &SpanContext) -> [u8; BINARY_FORMAT_LEN] {
+        let mut bytes = [0u8; BINARY_FORMAT_LEN];
+        bytes[0] = BINARY_FORMAT_VERSION;
+        bytes[1] = BINARY_TRACE_ID_FIELD_ID;
+        bytes[2..18].copy_from_slice(&span_context.trace_id().to_bytes());
+        bytes[18] = BINARY_SPAN_ID_FIELD_ID;
+        bytes[19..27].copy_from_slice(&span_context.span_id().to_bytes());
+        bytes[27] = BINARY_TRACE_OPTIONS_FIELD_ID;
+        bytes[28] = span_context.trace_flags().to_u8() & 0x1;
+        bytes
+    }
+}
+
+impl TextMapPropagator for GoogleBinaryTraceContextPropagator {
+    fn inject_context(&self, cx: &Context, injector: &mut dyn Injector) {
+        let span = cx.span();
+        let span_context = span.span_context();
+        if !span_context.is_valid() {
+            return;
+        }
+
+        let bytes = Self::encode_binary(span_context);
+        injector.set(GRPC_TRACE_BIN_HEADER, BASE64_ENGINE.encode(bytes));
+    }
+
+    fn extract_with_context(&self, cx: &Context, extractor: &dyn Extractor) -> Context {
+        self.extract_span_context(extractor)
+            .map(|sc| cx.with_remote_span_context(sc))
+            .unwrap_or_else(|_| cx.clone())
+    }
+
+    fn fields(&self) -> FieldIter<'_> {
+        FieldIter::new(GRPC_TRACE_BIN_HEADER_FIELDS.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::testing::trace::TestSpan;
+    use opentelemetry::trace::TraceState;
+    use std::collections::HashMap;
+
+    fn valid_binary_bytes() -> [u8; BINARY_FORMAT_LEN] {
+        let mut bytes = [0u8; BINARY_FORMAT_LEN];
+        bytes[0] = BINARY_FORMAT_VERSION;
+        bytes[1] = BINARY_TRACE_ID_FIELD_ID;
+        bytes[2..18].copy_from_slice(&[0x11; 16]);
+        bytes[18] = BINARY_SPAN_ID_FIELD_ID;
+        bytes[19..27].copy_from_slice(&[0x22; 8]);
+        bytes[27] = BINARY_TRACE_OPTIONS_FIELD_ID;
+        bytes[28] = 1;
+        bytes
+    }
+
+    #[test]
+    fn test_extract_span_context_valid() {
+        let propagator = GoogleBinaryTraceContextPropagator::new();
+        let mut headers = HashMap::new();
+        headers.insert(
+            GRPC_TRACE_BIN_HEADER.to_string(),
+            BASE64_ENGINE.encode(valid_binary_bytes()),
+        );
+
+        let span_context = propagator.extract_span_context(&headers).unwrap();
+        assert_eq!(format!("{:x}", span_context.trace_id()), "11".repeat(16));
+        assert_eq!(format!("{:x}", span_context.span_id()), "22".repeat(8));
+        assert!(span_context.is_sampled());
+    }
+
+    #[test]
+    fn test_extract_span_context_not_sampled() {
+        let propagator = GoogleBinaryTraceContextPropagator::new();
+        let mut bytes = valid_binary_bytes();
+        bytes[28] = 0;
+        let mut headers = HashMap::new();
+        headers.insert(GRPC_TRACE_BIN_HEADER.to_string(), BASE64_ENGINE.encode(bytes));
+
+        let span_context = propagator.extract_span_context(&headers).unwrap();
+        assert!(!span_context.is_sampled());
+    }
+
+    #[test]
+    fn test_extract_span_context_missing_header() {
+        let propagator = GoogleBinaryTraceContextPropagator::new();
+        let headers = HashMap::new();
+
+        assert!(propagator.extract_span_context(&headers).is_err());
+    }
+
+    #[test]
+    fn test_extract_span_context_wrong_version() {
+        let mut bytes = valid_binary_bytes();
+        bytes[0] = 1;
+        let mut headers = HashMap::new();
+        headers.insert(GRPC_TRACE_BIN_HEADER.to_string(), BASE64_ENGINE.encode(bytes));
+
+        let propagator = GoogleBinaryTraceContextPropagator::new();
+        assert!(propagator.extract_span_context(&headers).is_err());
+    }
+
+    #[test]
+    fn test_extract_span_context_truncated() {
+        let bytes = &valid_binary_bytes()[..20];
+        let mut headers = HashMap::new();
+        headers.insert(GRPC_TRACE_BIN_HEADER.to_string(), BASE64_ENGINE.encode(bytes));
+
+        let propagator = GoogleBinaryTraceContextPropagator::new();
+        assert!(propagator.extract_span_context(&headers).is_err());
+    }
+
+    #[test]
+    fn test_extract_span_context_zero_ids_rejected() {
+        let mut bytes = valid_binary_bytes();
+        bytes[2..18].copy_from_slice(&[0; 16]);
+        bytes[19..27].copy_from_slice(&[0; 8]);
+        let mut headers = HashMap::new();
+        headers.insert(GRPC_TRACE_BIN_HEADER.to_string(), BASE64_ENGINE.encode(bytes));
+
+        let propagator = GoogleBinaryTraceContextPropagator::new();
+        assert!(propagator.extract_span_context(&headers).is_err());
+    }
+
+    #[test]
+    fn test_inject_context_valid() {
+        let propagator = GoogleBinaryTraceContextPropagator::new();
+        let mut headers = HashMap::new();
+        let span = TestSpan(SpanContext::new(
+            TraceId::from_bytes([0x11; 16]),
+            SpanId::from_bytes([0x22; 8]),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ));
+        let cx = Context::current_with_span(span);
+
+        propagator.inject_context(&cx, &mut headers);
+        assert_eq!(
+            headers.get(GRPC_TRACE_BIN_HEADER),
+            Some(&BASE64_ENGINE.encode(valid_binary_bytes()))
+        );
+    }
+
+    #[test]
+    fn test_inject_then_extract_round_trip() {
+        let propagator = GoogleBinaryTraceContextPropagator::new();
+        let mut headers = HashMap::new();
+        let span = TestSpan(SpanContext::new(
+            TraceId::from_bytes([0xab; 16]),
+            SpanId::from_bytes([0xcd; 8]),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ));
+        let cx = Context::current_with_span(span);
+
+        propagator.inject_context(&cx, &mut headers);
+        let extracted = propagator.extract_span_context(&headers).unwrap();
+        assert_eq!(extracted.trace_id(), TraceId::from_bytes([0xab; 16]));
+        assert_eq!(extracted.span_id(), SpanId::from_bytes([0xcd; 8]));
+        assert!(extracted.is_sampled());
+    }
+}
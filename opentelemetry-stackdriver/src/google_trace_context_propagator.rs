@@ -8,10 +8,20 @@ use regex::Regex;
 /// Propagates span context in the Google Cloud Trace format,
 /// using the __X-Cloud-Trace-Context__ header.
 ///
+/// Optionally, it can also fall back to (on extraction) and emit (on injection) the
+/// standard W3C `traceparent` header, which is useful when migrating a fleet between
+/// GCP-native and W3C instrumentation. See [`GoogleTraceContextPropagatorBuilder`].
+///
 /// See https://cloud.google.com/trace/docs/setup/#force-trace for details on the format.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct GoogleTraceContextPropagator {
-    _private: (),
+    traceparent_fallback: bool,
+    inject_traceparent: bool,
+    tracestate: bool,
+    header_name: String,
+    emit_options_suffix: bool,
+    forced_sampled: Option<bool>,
+    fields: Vec<String>,
 }
 
 // https://cloud.google.com/trace/docs/setup/#force-trace
@@ -28,21 +38,162 @@ const CLOUD_TRACE_CONTEXT_HEADER: &str = "X-Cloud-Trace-Context";
 const GOOGLE_PROPAGATION_HEADER_VALUE_REGEX_STR: &str =
     r"^(?P<trace_id>[0-9a-f]{32})/(?P<span_id>[0-9]{1,20})(;o=(?P<trace_flags>[0-9]))?$";
 
-static TRACE_CONTEXT_HEADER_FIELDS: Lazy<[String; 1]> =
-    Lazy::new(|| [CLOUD_TRACE_CONTEXT_HEADER.to_owned()]);
+// https://www.w3.org/TR/trace-context/#traceparent-header
+// "00-<32 hex trace id>-<16 hex span id>-<2 hex flags>", version `00` is the only one defined so far
+const TRACEPARENT_HEADER: &str = "traceparent";
+const TRACEPARENT_VERSION: &str = "00";
+const TRACEPARENT_VALUE_REGEX_STR: &str =
+    r"^(?P<version>[0-9a-f]{2})-(?P<trace_id>[0-9a-f]{32})-(?P<span_id>[0-9a-f]{16})-(?P<trace_flags>[0-9a-f]{2})$";
 
 static GOOGLE_PROPAGATION_HEADER_VALUE_REGEX: Lazy<Option<Regex>> =
     Lazy::new(|| Regex::new(GOOGLE_PROPAGATION_HEADER_VALUE_REGEX_STR).ok());
 
+static TRACEPARENT_VALUE_REGEX: Lazy<Option<Regex>> =
+    Lazy::new(|| Regex::new(TRACEPARENT_VALUE_REGEX_STR).ok());
+
+// https://www.w3.org/TR/trace-context/#tracestate-header
+const TRACESTATE_HEADER: &str = "tracestate";
+
+/// Builder for [`GoogleTraceContextPropagator`].
+///
+/// Constructed via [`GoogleTraceContextPropagator::builder`].
+#[derive(Clone, Debug)]
+pub struct GoogleTraceContextPropagatorBuilder {
+    traceparent_fallback: bool,
+    inject_traceparent: bool,
+    tracestate: bool,
+    header_name: String,
+    emit_options_suffix: bool,
+    forced_sampled: Option<bool>,
+}
+
+impl Default for GoogleTraceContextPropagatorBuilder {
+    fn default() -> Self {
+        GoogleTraceContextPropagatorBuilder {
+            traceparent_fallback: false,
+            inject_traceparent: false,
+            tracestate: false,
+            header_name: CLOUD_TRACE_CONTEXT_HEADER.to_owned(),
+            emit_options_suffix: true,
+            forced_sampled: None,
+        }
+    }
+}
+
+impl GoogleTraceContextPropagatorBuilder {
+    /// When enabled, extraction falls back to parsing a standard W3C `traceparent`
+    /// header whenever the GCP trace context header (`X-Cloud-Trace-Context` unless
+    /// overridden with [`Self::with_header_name`]) is missing or fails to parse.
+    ///
+    /// Disabled by default.
+    pub fn with_traceparent_fallback(mut self, enabled: bool) -> Self {
+        self.traceparent_fallback = enabled;
+        self
+    }
+
+    /// When enabled, injection emits a W3C `traceparent` header in addition to the
+    /// GCP trace context header.
+    ///
+    /// Disabled by default.
+    pub fn with_traceparent_injection(mut self, enabled: bool) -> Self {
+        self.inject_traceparent = enabled;
+        self
+    }
+
+    /// When enabled, a companion W3C `tracestate` header is extracted and attached to the
+    /// constructed span context, and the span's existing `TraceState` is serialized back out
+    /// on injection. This preserves vendor tracestate entries across a hop that uses this
+    /// propagator, which otherwise hardcodes `TraceState::NONE`.
+    ///
+    /// Disabled by default.
+    pub fn with_tracestate(mut self, enabled: bool) -> Self {
+        self.tracestate = enabled;
+        self
+    }
+
+    /// Override the header name used for both extraction and injection of the GCP trace
+    /// context (defaults to `X-Cloud-Trace-Context`). Useful for gateways that only forward
+    /// headers under a different, case-sensitive name.
+    pub fn with_header_name(mut self, header_name: impl Into<String>) -> Self {
+        self.header_name = header_name.into();
+        self
+    }
+
+    /// When disabled, injection omits the `;o=<flags>` options suffix, emitting a bare
+    /// `trace_id/span_id` value instead. Enabled by default.
+    pub fn with_options_suffix(mut self, enabled: bool) -> Self {
+        self.emit_options_suffix = enabled;
+        self
+    }
+
+    /// Force a sampling decision on injection, overriding the span context's own
+    /// `TraceFlags`. Unset by default, meaning the span's own sampling decision is used.
+    pub fn with_forced_sampling(mut self, sampled: bool) -> Self {
+        self.forced_sampled = Some(sampled);
+        self
+    }
+
+    /// Build the configured [`GoogleTraceContextPropagator`].
+    pub fn build(self) -> GoogleTraceContextPropagator {
+        let mut fields = vec![self.header_name.clone()];
+        if self.traceparent_fallback || self.inject_traceparent {
+            fields.push(TRACEPARENT_HEADER.to_owned());
+        }
+        if self.tracestate {
+            fields.push(TRACESTATE_HEADER.to_owned());
+        }
+
+        GoogleTraceContextPropagator {
+            traceparent_fallback: self.traceparent_fallback,
+            inject_traceparent: self.inject_traceparent,
+            tracestate: self.tracestate,
+            header_name: self.header_name,
+            emit_options_suffix: self.emit_options_suffix,
+            forced_sampled: self.forced_sampled,
+            fields,
+        }
+    }
+}
+
 impl GoogleTraceContextPropagator {
-    /// Create a new `GoogleTraceContextPropagator`.
+    /// Create a new `GoogleTraceContextPropagator` with default settings (no `traceparent`
+    /// fallback or injection). Use [`GoogleTraceContextPropagator::builder`] to configure it.
     pub fn new() -> Self {
-        GoogleTraceContextPropagator { _private: () }
+        Self::builder().build()
+    }
+
+    /// Create a [`GoogleTraceContextPropagatorBuilder`] to configure a propagator.
+    pub fn builder() -> GoogleTraceContextPropagatorBuilder {
+        GoogleTraceContextPropagatorBuilder::default()
     }
 
     fn extract_span_context(&self, extractor: &dyn Extractor) -> Result<SpanContext, ()> {
+        let span_context = match self.extract_gcp_span_context(extractor) {
+            Ok(span_context) => span_context,
+            Err(()) if self.traceparent_fallback => Self::extract_traceparent(extractor)?,
+            Err(()) => return Err(()),
+        };
+
+        if self.tracestate {
+            if let Some(trace_state) = Self::extract_trace_state(extractor) {
+                return Ok(span_context.with_trace_state(trace_state));
+            }
+        }
+
+        Ok(span_context)
+    }
+
+    // parses the companion W3C `tracestate` header, if present and valid; absent or malformed
+    // tracestate is not itself an extraction failure, the GCP/traceparent span context still stands
+    fn extract_trace_state(extractor: &dyn Extractor) -> Option<TraceState> {
+        extractor
+            .get(TRACESTATE_HEADER)
+            .and_then(|header_value| header_value.parse::<TraceState>().ok())
+    }
+
+    fn extract_gcp_span_context(&self, extractor: &dyn Extractor) -> Result<SpanContext, ()> {
         let header_value = extractor
-            .get(CLOUD_TRACE_CONTEXT_HEADER)
+            .get(self.header_name.as_str())
             .map(|v| v.trim())
             .ok_or(())?;
 
@@ -70,6 +221,35 @@ impl GoogleTraceContextPropagator {
         Self::construct_span_context(trace_flags, trace_id_hex, span_id_dec)
     }
 
+    // parses a standard W3C traceparent header value, used as a fallback when the GCP
+    // header is absent or invalid
+    fn extract_traceparent(extractor: &dyn Extractor) -> Result<SpanContext, ()> {
+        let header_value = extractor
+            .get(TRACEPARENT_HEADER)
+            .map(|v| v.trim())
+            .ok_or(())?;
+
+        let regex = TRACEPARENT_VALUE_REGEX.as_ref().ok_or(())?;
+
+        let caps = regex.captures(header_value).ok_or(())?;
+
+        // only version `00` is currently defined by the W3C spec, reject anything else
+        if caps.name("version").map(|m| m.as_str()) != Some(TRACEPARENT_VERSION) {
+            return Err(());
+        }
+
+        let trace_id_hex = caps.name("trace_id").map(|m| m.as_str()).ok_or(())?;
+        let span_id_hex = caps.name("span_id").map(|m| m.as_str()).ok_or(())?;
+        let trace_flags_hex = caps.name("trace_flags").map(|m| m.as_str()).ok_or(())?;
+
+        let trace_id = TraceId::from_hex(trace_id_hex).map_err(|_| ())?;
+        let span_id = SpanId::from_hex(span_id_hex).map_err(|_| ())?;
+        let trace_flags =
+            TraceFlags::new(u8::from_str_radix(trace_flags_hex, 16).map_err(|_| ())?);
+
+        Self::build_span_context(trace_id, span_id, trace_flags)
+    }
+
     fn construct_span_context(
         trace_flags: TraceFlags,
         trace_id_hex: &str,
@@ -77,11 +257,24 @@ impl GoogleTraceContextPropagator {
     ) -> Result<SpanContext, ()> {
         let trace_id = TraceId::from_hex(trace_id_hex).map_err(|_| ())?;
 
+        // the regex allows up to 20 decimal digits, which can exceed u64::MAX, so we parse
+        // into a u128 first and truncate to the low 64 bits, matching how Cloud Trace itself
+        // wraps span ids rather than dropping the whole context on overflow
         let span_id = span_id_dec
-            .parse::<u64>()
-            .map(|v| SpanId::from_bytes(v.to_be_bytes())) // we can create SPAN ID only from bytes or hex string
+            .parse::<u128>()
+            .map(|v| SpanId::from_bytes((v as u64).to_be_bytes())) // we can create SPAN ID only from bytes or hex string
             .map_err(|_| ())?;
 
+        Self::build_span_context(trace_id, span_id, trace_flags)
+    }
+
+    // shared with `GoogleBinaryTraceContextPropagator`, which builds the `TraceId`/`SpanId`
+    // straight from raw bytes instead of parsing a textual header
+    pub(crate) fn build_span_context(
+        trace_id: TraceId,
+        span_id: SpanId,
+        trace_flags: TraceFlags,
+    ) -> Result<SpanContext, ()> {
         let span_context = SpanContext::new(trace_id, span_id, trace_flags, true, TraceState::NONE);
 
         // Ensure span is valid
@@ -93,20 +286,58 @@ impl GoogleTraceContextPropagator {
     }
 }
 
+impl Default for GoogleTraceContextPropagator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TextMapPropagator for GoogleTraceContextPropagator {
     fn inject_context(&self, cx: &Context, injector: &mut dyn Injector) {
         let span = cx.span();
         let span_context = span.span_context();
-        let sampled_flag = span_context.trace_flags().to_u8();
-        if span_context.is_valid() {
-            let header_value = format!(
+        if !span_context.is_valid() {
+            return;
+        }
+
+        let sampled_flag = self
+            .forced_sampled
+            .map(|sampled| if sampled { 1u8 } else { 0u8 })
+            .unwrap_or_else(|| span_context.trace_flags().to_u8());
+
+        let header_value = if self.emit_options_suffix {
+            format!(
                 "{:032x}/{};o={}",
                 span_context.trace_id(),
                 // at the moment we can only get span id as bytes
                 u64::from_be_bytes(span_context.span_id().to_bytes()),
                 sampled_flag
+            )
+        } else {
+            format!(
+                "{:032x}/{}",
+                span_context.trace_id(),
+                u64::from_be_bytes(span_context.span_id().to_bytes()),
+            )
+        };
+        injector.set(self.header_name.as_str(), header_value);
+
+        if self.inject_traceparent {
+            let traceparent_value = format!(
+                "{}-{:032x}-{:016x}-{:02x}",
+                TRACEPARENT_VERSION,
+                span_context.trace_id(),
+                span_context.span_id(),
+                sampled_flag
             );
-            injector.set(CLOUD_TRACE_CONTEXT_HEADER, header_value);
+            injector.set(TRACEPARENT_HEADER, traceparent_value);
+        }
+
+        if self.tracestate {
+            let trace_state_value = span_context.trace_state().header();
+            if !trace_state_value.is_empty() {
+                injector.set(TRACESTATE_HEADER, trace_state_value);
+            }
         }
     }
 
@@ -117,7 +348,7 @@ impl TextMapPropagator for GoogleTraceContextPropagator {
     }
 
     fn fields(&self) -> FieldIter<'_> {
-        FieldIter::new(TRACE_CONTEXT_HEADER_FIELDS.as_ref())
+        FieldIter::new(self.fields.as_ref())
     }
 }
 
@@ -264,6 +495,279 @@ mod tests {
         assert!(!new_cx.span().span_context().is_valid());
     }
 
+    #[test]
+    fn test_extract_span_context_max_u64_span_id() {
+        let propagator = GoogleTraceContextPropagator::new();
+        let mut headers = HashMap::new();
+        headers.insert(
+            CLOUD_TRACE_CONTEXT_HEADER.to_string().to_lowercase(),
+            "105445aa7843bc8bf206b12000100000/18446744073709551615;o=1".to_string(),
+        );
+
+        let span_context = propagator.extract_span_context(&headers).unwrap();
+        assert_eq!(
+            u64::from_be_bytes(span_context.span_id().to_bytes()),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_extract_span_context_span_id_overflowing_u64_truncates() {
+        let propagator = GoogleTraceContextPropagator::new();
+        let mut headers = HashMap::new();
+        // 20-digit value one above u64::MAX, should wrap around to 0
+        headers.insert(
+            CLOUD_TRACE_CONTEXT_HEADER.to_string().to_lowercase(),
+            "105445aa7843bc8bf206b12000100000/18446744073709551616;o=1".to_string(),
+        );
+
+        // trace id alone is valid, but a zero span id makes the whole span context invalid
+        assert!(propagator.extract_span_context(&headers).is_err());
+    }
+
+    #[test]
+    fn test_extract_span_context_span_id_overflowing_u64_truncates_nonzero() {
+        let propagator = GoogleTraceContextPropagator::new();
+        let mut headers = HashMap::new();
+        // u64::MAX + 2, wraps around to 1
+        headers.insert(
+            CLOUD_TRACE_CONTEXT_HEADER.to_string().to_lowercase(),
+            "105445aa7843bc8bf206b12000100000/18446744073709551617;o=1".to_string(),
+        );
+
+        let span_context = propagator.extract_span_context(&headers).unwrap();
+        assert_eq!(u64::from_be_bytes(span_context.span_id().to_bytes()), 1);
+    }
+
+    #[test]
+    fn test_extract_span_context_traceparent_fallback() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_traceparent_fallback(true)
+            .build();
+        let mut headers = HashMap::new();
+        headers.insert(
+            TRACEPARENT_HEADER.to_string().to_lowercase(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string(),
+        );
+
+        let span_context = propagator.extract_span_context(&headers).unwrap();
+        assert_eq!(
+            format!("{:x}", span_context.trace_id()),
+            "4bf92f3577b34da6a3ce929d0e0e4736"
+        );
+        assert_eq!(format!("{:016x}", span_context.span_id()), "00f067aa0ba902b7");
+        assert!(span_context.is_sampled());
+    }
+
+    #[test]
+    fn test_extract_span_context_traceparent_fallback_disabled_by_default() {
+        let propagator = GoogleTraceContextPropagator::new();
+        let mut headers = HashMap::new();
+        headers.insert(
+            TRACEPARENT_HEADER.to_string().to_lowercase(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string(),
+        );
+
+        assert!(propagator.extract_span_context(&headers).is_err());
+    }
+
+    #[test]
+    fn test_extract_span_context_gcp_header_takes_priority_over_traceparent() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_traceparent_fallback(true)
+            .build();
+        let mut headers = HashMap::new();
+        headers.insert(
+            CLOUD_TRACE_CONTEXT_HEADER.to_string().to_lowercase(),
+            "105445aa7843bc8bf206b12000100000/1;o=1".to_string(),
+        );
+        headers.insert(
+            TRACEPARENT_HEADER.to_string().to_lowercase(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string(),
+        );
+
+        let span_context = propagator.extract_span_context(&headers).unwrap();
+        assert_eq!(
+            format!("{:x}", span_context.trace_id()),
+            "105445aa7843bc8bf206b12000100000"
+        );
+    }
+
+    #[test]
+    fn test_inject_context_with_traceparent_injection() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_traceparent_injection(true)
+            .build();
+        let mut headers = HashMap::new();
+        let span = TestSpan(SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ));
+        let cx = Context::current_with_span(span);
+
+        propagator.inject_context(&cx, &mut headers);
+        assert_eq!(
+            headers.get(TRACEPARENT_HEADER.to_lowercase().as_str()),
+            Some(&"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fields_includes_traceparent_when_enabled() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_traceparent_fallback(true)
+            .build();
+
+        let fields: Vec<&str> = propagator.fields().collect();
+        assert!(fields.contains(&CLOUD_TRACE_CONTEXT_HEADER));
+        assert!(fields.contains(&TRACEPARENT_HEADER));
+    }
+
+    #[test]
+    fn test_extract_span_context_tracestate_attached_when_enabled() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_tracestate(true)
+            .build();
+        let mut headers = HashMap::new();
+        headers.insert(
+            CLOUD_TRACE_CONTEXT_HEADER.to_string().to_lowercase(),
+            "105445aa7843bc8bf206b12000100000/1;o=1".to_string(),
+        );
+        headers.insert(
+            TRACESTATE_HEADER.to_string().to_lowercase(),
+            "vendor1=value1,vendor2=value2".to_string(),
+        );
+
+        let span_context = propagator.extract_span_context(&headers).unwrap();
+        assert_eq!(span_context.trace_state().header(), "vendor1=value1,vendor2=value2");
+    }
+
+    #[test]
+    fn test_extract_span_context_tracestate_ignored_when_disabled() {
+        let propagator = GoogleTraceContextPropagator::new();
+        let mut headers = HashMap::new();
+        headers.insert(
+            CLOUD_TRACE_CONTEXT_HEADER.to_string().to_lowercase(),
+            "105445aa7843bc8bf206b12000100000/1;o=1".to_string(),
+        );
+        headers.insert(
+            TRACESTATE_HEADER.to_string().to_lowercase(),
+            "vendor1=value1".to_string(),
+        );
+
+        let span_context = propagator.extract_span_context(&headers).unwrap();
+        assert_eq!(span_context.trace_state().header(), "");
+    }
+
+    #[test]
+    fn test_inject_context_with_tracestate() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_tracestate(true)
+            .build();
+        let mut headers = HashMap::new();
+        let trace_state = "vendor1=value1,vendor2=value2"
+            .parse::<TraceState>()
+            .unwrap();
+        let span = TestSpan(SpanContext::new(
+            TraceId::from_hex("105445aa7843bc8bf206b12000100000").unwrap(),
+            SpanId::from_hex("0000000000000001").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            trace_state,
+        ));
+        let cx = Context::current_with_span(span);
+
+        propagator.inject_context(&cx, &mut headers);
+        assert_eq!(
+            headers.get(TRACESTATE_HEADER.to_lowercase().as_str()),
+            Some(&"vendor1=value1,vendor2=value2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fields_includes_tracestate_when_enabled() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_tracestate(true)
+            .build();
+
+        let fields: Vec<&str> = propagator.fields().collect();
+        assert!(fields.contains(&CLOUD_TRACE_CONTEXT_HEADER));
+        assert!(fields.contains(&TRACESTATE_HEADER));
+    }
+
+    #[test]
+    fn test_inject_context_without_options_suffix() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_options_suffix(false)
+            .build();
+        let mut headers = HashMap::new();
+        let span = TestSpan(SpanContext::new(
+            TraceId::from_hex("105445aa7843bc8bf206b12000100000").unwrap(),
+            SpanId::from_hex("0000000000000001").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ));
+        let cx = Context::current_with_span(span);
+
+        propagator.inject_context(&cx, &mut headers);
+        assert_eq!(
+            headers.get(CLOUD_TRACE_CONTEXT_HEADER.to_lowercase().as_str()),
+            Some(&"105445aa7843bc8bf206b12000100000/1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_inject_and_extract_with_custom_header_name() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_header_name("X-Custom-Trace-Context")
+            .build();
+        let mut headers = HashMap::new();
+        let span = TestSpan(SpanContext::new(
+            TraceId::from_hex("105445aa7843bc8bf206b12000100000").unwrap(),
+            SpanId::from_hex("0000000000000001").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        ));
+        let cx = Context::current_with_span(span);
+
+        propagator.inject_context(&cx, &mut headers);
+        assert_eq!(
+            headers.get("x-custom-trace-context"),
+            Some(&"105445aa7843bc8bf206b12000100000/1;o=1".to_string())
+        );
+        assert!(!headers.contains_key(CLOUD_TRACE_CONTEXT_HEADER.to_lowercase().as_str()));
+
+        let extracted = propagator.extract_span_context(&headers).unwrap();
+        assert!(extracted.is_sampled());
+    }
+
+    #[test]
+    fn test_inject_context_with_forced_sampling() {
+        let propagator = GoogleTraceContextPropagator::builder()
+            .with_forced_sampling(true)
+            .build();
+        let mut headers = HashMap::new();
+        let span = TestSpan(SpanContext::new(
+            TraceId::from_hex("105445aa7843bc8bf206b12000100000").unwrap(),
+            SpanId::from_hex("0000000000000001").unwrap(),
+            TraceFlags::NOT_SAMPLED,
+            true,
+            TraceState::default(),
+        ));
+        let cx = Context::current_with_span(span);
+
+        propagator.inject_context(&cx, &mut headers);
+        assert_eq!(
+            headers.get(CLOUD_TRACE_CONTEXT_HEADER.to_lowercase().as_str()),
+            Some(&"105445aa7843bc8bf206b12000100000/1;o=1".to_string())
+        );
+    }
+
     #[test]
     fn test_extract_with_context_invalid_span_id() {
         let propagator = GoogleTraceContextPropagator::new();